@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use partially::Partial;
 
-use crate::{runner::{OptionValue, PartialRunState, Positional, RunState, Token}, shared::Arg};
+use crate::{help::CommandMetadata, runner::{OptionValue, PartialRunState, Positional, RunState, Token}, shared::Arg};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Reducer {
@@ -11,6 +11,7 @@ pub enum Reducer {
     PushBatch,
     PushBound,
     PushExtra,
+    PushGluedValue(HashSet<String>, HashSet<String>),
     PushFalse(String),
     PushNone(String),
     PushPath,
@@ -20,9 +21,12 @@ pub enum Reducer {
     PushTrue(String),
     SetCandidateState(PartialRunState),
     SetError(String),
+    SetHelpText(CommandMetadata),
+    SetMissingRequiredOption(Vec<String>),
     SetOptionArityError,
     SetSelectedIndex(isize),
     SetStringValue,
+    SetUnknownOptionError(HashSet<String>),
     UseHelp(usize),
 }
 
@@ -39,11 +43,13 @@ pub enum Check {
     IsBoundOption(HashSet<String>),
     IsExact(String),
     IsExactString(String),
+    IsGluedOption(HashSet<String>, HashSet<String>),
     IsHelp,
+    IsMissingRequiredOption(Vec<String>),
     IsNotOptionLike,
     IsOptionLike,
     IsUnsupportedOption(HashSet<String>),
-    IsInvalidOption,
+    IsInvalidOption(HashSet<String>),
 }
 
 pub fn apply_reducer(reducer: &Reducer, state: &RunState, arg: &Arg, segment_index: usize) -> RunState {
@@ -118,6 +124,62 @@ pub fn apply_reducer(reducer: &Reducer, state: &RunState, arg: &Arg, segment_ind
             state
         }
 
+        Reducer::PushGluedValue(boolean_options, value_options) => {
+            let arg = arg.unwrap_user();
+            let mut state = state.clone();
+
+            // Walk char-by-char (never raw byte ranges) so a multi-byte character
+            // can't land us mid-character and panic on a slice.
+            let chars: Vec<(usize, char)> = arg.char_indices().collect();
+            let split_at = find_glued_split(&chars, boolean_options, value_options).unwrap();
+
+            for (i, &(byte_start, c)) in chars.iter().enumerate().take(split_at).skip(1) {
+                let name = format!("-{}", c);
+
+                let slice = match i == 1 {
+                    true => (0, byte_start + c.len_utf8()),
+                    false => (byte_start, byte_start + c.len_utf8()),
+                };
+
+                state.options.push((
+                    name.clone(),
+                    OptionValue::Bool(true),
+                ));
+
+                state.tokens.push(Token::Option {
+                    segment_index,
+                    slice: Some(slice),
+                    option: name,
+                });
+            }
+
+            let (byte_start, c) = chars[split_at];
+            let name = format!("-{}", c);
+
+            let (option_slice, value_start) = match split_at == 1 {
+                true => ((0, byte_start + c.len_utf8()), byte_start + c.len_utf8()),
+                false => ((byte_start, byte_start + c.len_utf8()), byte_start + c.len_utf8()),
+            };
+
+            state.tokens.push(Token::Option {
+                segment_index,
+                slice: Some(option_slice),
+                option: name.clone(),
+            });
+
+            state.tokens.push(Token::Value {
+                segment_index,
+                slice: Some((value_start, arg.len())),
+            });
+
+            state.options.push((
+                name,
+                OptionValue::String(arg[value_start..].to_string()),
+            ));
+
+            state
+        }
+
         Reducer::PushFalse(name) => {
             let mut state = state.clone();
 
@@ -229,6 +291,21 @@ pub fn apply_reducer(reducer: &Reducer, state: &RunState, arg: &Arg, segment_ind
             state
         }
 
+        Reducer::SetHelpText(metadata) => {
+            let mut state = state.clone();
+            state.error_message = metadata.help();
+            state
+        }
+
+        Reducer::SetMissingRequiredOption(required) => {
+            let present: Vec<_> = state.options.iter().map(|(name, _)| name.clone()).collect();
+            let missing = missing_required_options(required, &present);
+
+            let mut state = state.clone();
+            state.error_message = format!("Missing required option {}.", missing.join(", "));
+            state
+        }
+
         Reducer::SetOptionArityError => {
             let last_option_name = &state.options.last().unwrap().0;
 
@@ -258,6 +335,18 @@ pub fn apply_reducer(reducer: &Reducer, state: &RunState, arg: &Arg, segment_ind
             state
         }
 
+        Reducer::SetUnknownOptionError(options) => {
+            let arg = arg.unwrap_user();
+            let mut state = state.clone();
+
+            state.error_message = match closest_option(arg, options) {
+                Some(candidate) => format!("Unknown option \"{}\". Did you mean \"{}\"?", arg, candidate),
+                None => format!("Unknown option \"{}\".", arg),
+            };
+
+            state
+        }
+
         Reducer::UseHelp(index) => {
             let mut state = state.clone();
             state.options = vec![("-c".to_string(), OptionValue::String(format!("{}", *index)))];
@@ -276,6 +365,63 @@ pub fn apply_reducer(reducer: &Reducer, state: &RunState, arg: &Arg, segment_ind
     }
 }
 
+// Finds the first character position (1-based, after the leading `-`) where
+// a run of known boolean options gives way to a known value-taking option,
+// requiring at least one character left over to serve as that option's glued
+// value (so e.g. the bare `-xvf`, with nothing appended, doesn't match).
+fn find_glued_split(chars: &[(usize, char)], boolean_options: &HashSet<String>, value_options: &HashSet<String>) -> Option<usize> {
+    if chars.len() <= 2 {
+        return None;
+    }
+
+    (1..chars.len() - 1).find(|&i| {
+        chars[1..i].iter().all(|&(_, c)| boolean_options.contains(&format!("-{}", c)))
+            && value_options.contains(&format!("-{}", chars[i].1))
+    })
+}
+
+// Returns the subset of `required` (in declaration order) that doesn't
+// appear anywhere in `present`, so every missing option can be reported at
+// once instead of one run at a time. Shared by `Check::IsMissingRequiredOption`
+// and `Reducer::SetMissingRequiredOption`, which each derive `present` from
+// `state.options` independently.
+//
+// NOTE: nothing yet populates `required` from a real `#[cli::option(...,
+// required = true)]` attribute or calls these at end-of-input — that wiring
+// belongs to the derive-macro and runner crates, which aren't part of this
+// source tree snapshot.
+fn missing_required_options(required: &[String], present: &[String]) -> Vec<String> {
+    required.iter()
+        .filter(|name| !present.contains(name))
+        .cloned()
+        .collect()
+}
+
+fn looks_like_negative_number(arg: &str) -> bool {
+    let rest = match arg.strip_prefix('-') {
+        Some(rest) => rest,
+        None => return false,
+    };
+
+    let (mantissa, exponent) = match rest.find(['e', 'E']) {
+        Some(i) => (&rest[..i], Some(&rest[i + 1..])),
+        None => (rest, None),
+    };
+
+    let (int_part, frac_part) = match mantissa.find('.') {
+        Some(i) => (&mantissa[..i], Some(&mantissa[i + 1..])),
+        None => (mantissa, None),
+    };
+
+    let is_digits = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+
+    let is_exponent = |s: &str| is_digits(s.strip_prefix(['+', '-']).unwrap_or(s));
+
+    is_digits(int_part)
+        && frac_part.map_or(true, is_digits)
+        && exponent.map_or(true, is_exponent)
+}
+
 fn is_valid_option(option: &str) -> bool {
     if option.starts_with("--") {
         option.chars().skip(2).all(|c| c.is_alphanumeric() || c == '-')
@@ -286,6 +432,48 @@ fn is_valid_option(option: &str) -> bool {
     }
 }
 
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    // Keep the shorter string as the row we iterate over so we only ever
+    // need two rolling rows of length min(m, n) + 1.
+    let (a, b) = match a.chars().count() <= b.chars().count() {
+        true => (a, b),
+        false => (b, a),
+    };
+
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut previous_row: Vec<usize> = (0..=a.len()).collect();
+    let mut current_row = vec![0; a.len() + 1];
+
+    for (j, b_char) in b.iter().enumerate() {
+        current_row[0] = j + 1;
+
+        for (i, a_char) in a.iter().enumerate() {
+            let cost = if a_char == b_char { 0 } else { 1 };
+
+            current_row[i + 1] = (previous_row[i + 1] + 1)
+                .min(current_row[i] + 1)
+                .min(previous_row[i] + cost);
+        }
+
+        std::mem::swap(&mut previous_row, &mut current_row);
+    }
+
+    previous_row[a.len()]
+}
+
+fn closest_option(token: &str, candidates: &HashSet<String>) -> Option<String> {
+    let closest = candidates.iter()
+        .map(|candidate| (levenshtein_distance(token, candidate), candidate))
+        .min_by(|(da, a), (db, b)| da.cmp(db).then_with(|| a.cmp(b)))?;
+
+    let (distance, candidate) = closest;
+    let threshold = std::cmp::max(2, token.chars().count() / 3);
+
+    (distance <= threshold).then(|| candidate.clone())
+}
+
 pub fn apply_check(check: &Check, state: &RunState, arg: &Arg, _segment_index: usize) -> bool {
     match check {
         Check::Always => true,
@@ -308,6 +496,17 @@ pub fn apply_check(check: &Check, state: &RunState, arg: &Arg, _segment_index: u
             !state.ignore_options && arg == needle
         }
 
+        // Must be registered ahead of `IsBatchOption` so that a value-taking option
+        // at the tail of a cluster (e.g. `-xvffoo`) isn't misparsed as all-boolean.
+        Check::IsGluedOption(boolean_options, value_options) => {
+            let arg = arg.unwrap_user();
+
+            !state.ignore_options && arg.starts_with('-') && {
+                let chars: Vec<(usize, char)> = arg.char_indices().collect();
+                find_glued_split(&chars, boolean_options, value_options).is_some()
+            }
+        }
+
         Check::IsHelp => {
             let arg = arg.unwrap_user();
             !state.ignore_options && (arg == "--help" || arg == "-h" || arg.starts_with("--help="))
@@ -318,24 +517,178 @@ pub fn apply_check(check: &Check, state: &RunState, arg: &Arg, _segment_index: u
             !state.ignore_options && arg == needle.as_str()
         }
 
+        Check::IsMissingRequiredOption(required) => {
+            let present: Vec<_> = state.options.iter().map(|(name, _)| name.clone()).collect();
+
+            matches!(arg, Arg::EndOfInput | Arg::EndOfPartialInput)
+                && !missing_required_options(required, &present).is_empty()
+        }
+
         Check::IsNotOptionLike => {
             let arg = arg.unwrap_user();
-            state.ignore_options || arg == "-" || !arg.starts_with('-')
+            state.ignore_options || arg == "-" || !arg.starts_with('-') || looks_like_negative_number(arg)
         }
 
         Check::IsOptionLike => {
             let arg = arg.unwrap_user();
-            !state.ignore_options && arg != "-" && arg.starts_with('-')
+            !state.ignore_options && arg != "-" && arg.starts_with('-') && !looks_like_negative_number(arg)
         }
 
         Check::IsUnsupportedOption(options) => {
             let arg = arg.unwrap_user();
-            !state.ignore_options && arg.starts_with("-") && is_valid_option(arg) && !options.contains(arg)
+            !state.ignore_options && arg.starts_with("-") && !looks_like_negative_number(arg) && is_valid_option(arg) && !options.contains(arg)
         }
 
-        Check::IsInvalidOption => {
+        Check::IsInvalidOption(_options) => {
             let arg = arg.unwrap_user();
-            !state.ignore_options && arg.starts_with("-") && !is_valid_option(arg)
+            !state.ignore_options && arg.starts_with("-") && !looks_like_negative_number(arg) && !is_valid_option(arg)
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(names: &[&str]) -> HashSet<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    fn split(arg: &str, boolean_options: &HashSet<String>, value_options: &HashSet<String>) -> Option<usize> {
+        let chars: Vec<(usize, char)> = arg.char_indices().collect();
+        find_glued_split(&chars, boolean_options, value_options)
+    }
+
+    #[test]
+    fn set_help_text_renders_metadata_into_error_message() {
+        let metadata = CommandMetadata {
+            path: vec!["cp".to_string()],
+            options: vec![],
+            positionals: vec![],
+        };
+
+        let state = apply_reducer(
+            &Reducer::SetHelpText(metadata.clone()),
+            &RunState::default(),
+            &Arg::User("--help".to_string()),
+            0,
+        );
+
+        assert_eq!(state.error_message, metadata.help());
+    }
+
+    #[test]
+    fn glued_split_finds_tail_value_option() {
+        let booleans = set(&["-x", "-v"]);
+        let values = set(&["-f"]);
+        assert_eq!(split("-xvffoo", &booleans, &values), Some(3));
+    }
+
+    #[test]
+    fn glued_split_handles_bare_degenerate_form() {
+        let booleans = set(&["-x"]);
+        let values = set(&["-o"]);
+        assert_eq!(split("-ofoo", &booleans, &values), Some(1));
+    }
+
+    #[test]
+    fn glued_split_rejects_unregistered_boolean_prefix() {
+        let booleans = set(&["-x", "-v"]);
+        let values = set(&["-f"]);
+        assert_eq!(split("-qffoo", &booleans, &values), None);
+    }
+
+    #[test]
+    fn glued_split_rejects_no_remaining_value() {
+        let booleans = set(&["-x", "-v"]);
+        let values = set(&["-f"]);
+        assert_eq!(split("-xvf", &booleans, &values), None);
+    }
+
+    #[test]
+    fn glued_split_does_not_panic_on_multibyte_chars() {
+        let booleans = set(&["-x", "-v"]);
+        let values = set(&["-f"]);
+        assert_eq!(split("-café", &booleans, &values), None);
+    }
+
+    #[test]
+    fn missing_required_options_preserves_declaration_order() {
+        let required = vec!["--foo".to_string(), "--bar".to_string(), "--baz".to_string()];
+        let present = vec!["--bar".to_string()];
+
+        assert_eq!(
+            missing_required_options(&required, &present),
+            vec!["--foo".to_string(), "--baz".to_string()],
+        );
+    }
+
+    #[test]
+    fn missing_required_options_empty_when_all_present() {
+        let required = vec!["--foo".to_string()];
+        let present = vec!["--foo".to_string()];
+
+        assert!(missing_required_options(&required, &present).is_empty());
+    }
+
+    #[test]
+    fn looks_like_negative_number_accepts_plain_and_exponent_forms() {
+        assert!(looks_like_negative_number("-5"));
+        assert!(looks_like_negative_number("-1.5"));
+        assert!(looks_like_negative_number("-1e3"));
+        assert!(looks_like_negative_number("-5e-3"));
+    }
+
+    #[test]
+    fn looks_like_negative_number_rejects_a_dangling_decimal_point() {
+        // "-1." has no fractional digits after the point.
+        assert!(!looks_like_negative_number("-1."));
+    }
+
+    #[test]
+    fn looks_like_negative_number_rejects_a_missing_integer_part() {
+        // "-.5" has no digits before the point.
+        assert!(!looks_like_negative_number("-.5"));
+    }
+
+    #[test]
+    fn looks_like_negative_number_rejects_a_dangling_exponent() {
+        // "-5e" has no digits after the exponent marker.
+        assert!(!looks_like_negative_number("-5e"));
+    }
+
+    #[test]
+    fn looks_like_negative_number_rejects_a_double_dash() {
+        // "--5" looks like a long option, not a negative number.
+        assert!(!looks_like_negative_number("--5"));
+    }
+
+    #[test]
+    fn looks_like_negative_number_accepts_a_signed_exponent() {
+        assert!(looks_like_negative_number("-5e-3"));
+    }
+
+    #[test]
+    fn levenshtein_distance_counts_two_substitutions() {
+        assert_eq!(levenshtein_distance("--fee", "--fix"), 2);
+    }
+
+    #[test]
+    fn closest_option_suggests_the_nearest_known_option() {
+        let candidates = set(&["--fix", "--verbose", "--output"]);
+        assert_eq!(closest_option("--fee", &candidates), Some("--fix".to_string()));
+    }
+
+    #[test]
+    fn closest_option_breaks_ties_on_the_lexicographically_smaller_candidate() {
+        // "--fon" and "--foo" are both a single substitution away from "--fop".
+        let candidates = set(&["--foo", "--fon"]);
+        assert_eq!(closest_option("--fop", &candidates), Some("--fon".to_string()));
+    }
+
+    #[test]
+    fn closest_option_falls_back_to_none_past_the_threshold() {
+        let candidates = set(&["--abc"]);
+        assert_eq!(closest_option("--xyz", &candidates), None);
+    }
+}
@@ -0,0 +1,146 @@
+/// The declared arity of a positional argument, mirroring the `Positional`
+/// values a successful parse produces in `RunState`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PositionalArity {
+    Required,
+    Optional,
+    Rest,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PositionalMetadata {
+    pub name: String,
+    pub arity: PositionalArity,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptionMetadata {
+    /// All the names this option can be invoked as, e.g. `["-r", "--recursive"]`.
+    pub names: Vec<String>,
+    pub help: Option<String>,
+    pub required: bool,
+}
+
+/// Everything the help/usage formatter needs about a single registered
+/// command: its path, options and positionals.
+///
+/// `Reducer::SetHelpText(CommandMetadata)` renders `help()` into
+/// `state.error_message`, the field `SetError` also writes. Nothing in this
+/// source tree builds a `CommandMetadata` from the `#[cli::command]` or
+/// `#[cli::option]` attributes, and no rule here pairs `Check::IsHelp` with
+/// `SetHelpText`; a `CommandMetadata` exists only where a caller constructs one.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CommandMetadata {
+    pub path: Vec<String>,
+    pub options: Vec<OptionMetadata>,
+    pub positionals: Vec<PositionalMetadata>,
+}
+
+impl CommandMetadata {
+    /// Renders the one-line synopsis, e.g. `cp [-r,--recursive] <sources...> <destination>`.
+    pub fn usage(&self) -> String {
+        let mut parts = vec![self.path.join(" ")];
+
+        for option in &self.options {
+            parts.push(format!("[{}]", option.names.join(",")));
+        }
+
+        for positional in &self.positionals {
+            parts.push(match positional.arity {
+                PositionalArity::Required => format!("<{}>", positional.name),
+                PositionalArity::Optional => format!("[{}]", positional.name),
+                PositionalArity::Rest => format!("<{}...>", positional.name),
+            });
+        }
+
+        parts.join(" ")
+    }
+
+    /// Renders the full `--help` output: the synopsis followed by an
+    /// aligned list of the options that carry a `help` string.
+    pub fn help(&self) -> String {
+        let mut lines = vec![self.usage()];
+
+        let documented: Vec<_> = self.options.iter()
+            .filter(|option| option.help.is_some())
+            .collect();
+
+        if !documented.is_empty() {
+            lines.push(String::new());
+
+            let width = documented.iter()
+                .map(|option| option.names.join(",").len())
+                .max()
+                .unwrap_or(0);
+
+            for option in documented {
+                lines.push(format!(
+                    "  {:width$}  {}",
+                    option.names.join(","),
+                    option.help.as_deref().unwrap_or(""),
+                    width = width,
+                ));
+            }
+        }
+
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cp_metadata() -> CommandMetadata {
+        CommandMetadata {
+            path: vec!["cp".to_string()],
+            options: vec![OptionMetadata {
+                names: vec!["-r".to_string(), "--recursive".to_string()],
+                help: Some("Copy directories recursively".to_string()),
+                required: false,
+            }],
+            positionals: vec![
+                PositionalMetadata { name: "sources".to_string(), arity: PositionalArity::Rest },
+                PositionalMetadata { name: "destination".to_string(), arity: PositionalArity::Required },
+            ],
+        }
+    }
+
+    #[test]
+    fn usage_renders_options_and_positionals() {
+        assert_eq!(
+            cp_metadata().usage(),
+            "cp [-r,--recursive] <sources...> <destination>",
+        );
+    }
+
+    #[test]
+    fn usage_renders_optional_positionals_in_brackets() {
+        let metadata = CommandMetadata {
+            path: vec!["greet".to_string()],
+            options: vec![],
+            positionals: vec![PositionalMetadata { name: "name".to_string(), arity: PositionalArity::Optional }],
+        };
+
+        assert_eq!(metadata.usage(), "greet [name]");
+    }
+
+    #[test]
+    fn help_appends_aligned_documented_options() {
+        assert_eq!(
+            cp_metadata().help(),
+            "cp [-r,--recursive] <sources...> <destination>\n\n  -r,--recursive  Copy directories recursively",
+        );
+    }
+
+    #[test]
+    fn help_skips_the_option_list_when_nothing_is_documented() {
+        let metadata = CommandMetadata {
+            path: vec!["noop".to_string()],
+            options: vec![OptionMetadata { names: vec!["-x".to_string()], help: None, required: false }],
+            positionals: vec![],
+        };
+
+        assert_eq!(metadata.help(), "noop [-x]");
+    }
+}